@@ -0,0 +1,169 @@
+//! A cursor over raw `[u8]` slices that may contain invalid UTF-8
+//!
+//! Parsers that read from network buffers or mmap'd files often can't assume
+//! well-formed UTF-8 up front. [`ByteCursor`] mirrors [`crate::StrCursor`] but
+//! validates lazily as `head` advances, so malformed input can be tolerated
+//! without pre-converting the whole buffer with [`String::from_utf8_lossy`].
+
+use crate::spanner::Spanner;
+use crate::ByteSpan;
+
+/// Expected length, in bytes, of the UTF-8 scalar value starting with `lead`
+///
+/// Returns `1` for a byte that can't start a valid sequence, so that byte alone
+/// is reported as the invalid subsequence.
+fn utf8_seq_len(lead: u8) -> usize {
+    match lead {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 1,
+    }
+}
+
+/// A cursor on `[u8]` slices, validating UTF-8 lazily as `head` advances
+///
+/// See the [module-level documentation](self) for why this exists, and
+/// [`StrCursor`](crate::StrCursor) for the highlight/tail/head terminology it reuses.
+#[derive(Debug, Clone, Copy)]
+pub struct ByteCursor<'s, S> {
+    base: &'s [u8],
+    highlight_length: usize,
+    base_offset: usize,
+    pub spanner_tail: S,
+    pub spanner_head: S,
+}
+
+impl<'s, S: Spanner + Default> ByteCursor<'s, S> {
+    /// Creates a new cursor with the default value of the spanner
+    pub fn new(s: &'s [u8]) -> Self {
+        Self::with_spanner(s, Default::default())
+    }
+}
+
+impl<'s, S: Spanner> ByteCursor<'s, S> {
+    /// Creates a cursor with the provided spanner
+    pub fn with_spanner(s: &'s [u8], spanner: S) -> Self {
+        Self {
+            base: s,
+            highlight_length: 0,
+            base_offset: 0,
+            spanner_head: spanner.clone(),
+            spanner_tail: spanner,
+        }
+    }
+
+    /// Indicates if the current highlight is empty
+    pub fn highlight_empty(&mut self) -> bool {
+        self.highlight_length == 0
+    }
+
+    /// Indicates if the post slice (that is `[head..]`) is empty
+    pub fn post_empty(&mut self) -> bool {
+        self.highlight_length == self.base.len()
+    }
+
+    /// Returns the current highlight
+    pub fn highlight(&self) -> &'s [u8] {
+        unsafe { self.base.get_unchecked(..self.highlight_length) }
+    }
+
+    /// Returns the post slice
+    pub fn post(&self) -> &'s [u8] {
+        unsafe { self.base.get_unchecked(self.highlight_length..) }
+    }
+
+    /// Returns the length of the maximal prefix of the post slice that is valid UTF-8
+    ///
+    /// Behaves like [`std::str::Utf8Error::valid_up_to`]: callers can use it to
+    /// highlight the longest valid chunk before having to deal with the error.
+    pub fn valid_up_to(&self) -> usize {
+        match std::str::from_utf8(self.post()) {
+            Ok(s) => s.len(),
+            Err(e) => e.valid_up_to(),
+        }
+    }
+
+    /// Advances `head` by one scalar value
+    ///
+    /// Returns `Ok(None)` if `head` is already at the end of the underlying slice,
+    /// and `Ok(Some(c))` after decoding and stepping over a valid `char`.
+    ///
+    /// If `head` sits on a byte sequence that isn't valid UTF-8, returns `Err` with
+    /// the [`ByteSpan`] of the invalid subsequence, mirroring
+    /// [`std::str::Utf8Error::error_len`] (or, if the tail is simply truncated
+    /// mid-sequence, the remaining bytes). `head` is *not* advanced in that case;
+    /// call [`ByteCursor::skip_invalid`] with the returned span to resume past it.
+    ///
+    /// Only decodes the handful of bytes the leading byte says the next scalar
+    /// value should span, rather than re-validating the whole post slice, so
+    /// stepping through a buffer one `char` at a time stays linear overall.
+    pub fn step(&mut self) -> Result<Option<char>, ByteSpan> {
+        let post = self.post();
+        let Some(&lead) = post.first() else {
+            return Ok(None);
+        };
+        let len = utf8_seq_len(lead).min(post.len());
+        match std::str::from_utf8(&post[..len]) {
+            Ok(s) => Ok(self.step_valid(s)),
+            Err(e) => {
+                let start = self.base_offset + self.highlight_length;
+                // `error_len` is `None` when the window is merely an incomplete
+                // prefix of a valid sequence (truncated tail); in that case the
+                // whole window is unresolvable invalid data, not `len` unconditionally.
+                let invalid_len = e.error_len().unwrap_or(len);
+                Err(ByteSpan {
+                    start,
+                    end: start + invalid_len,
+                })
+            }
+        }
+    }
+
+    fn step_valid(&mut self, valid: &str) -> Option<char> {
+        let c = valid.chars().next()?;
+        self.highlight_length += c.len_utf8();
+        self.spanner_head.forward(c);
+        Some(c)
+    }
+
+    /// Advances `head` past an invalid subsequence previously reported by [`ByteCursor::step`]
+    ///
+    /// The spanner is left untouched, since an invalid byte sequence isn't a `char`
+    /// it can be asked to track.
+    pub fn skip_invalid(&mut self, span: ByteSpan) {
+        self.highlight_length += span.end - span.start;
+    }
+
+    /// Validate the current highlight
+    ///
+    /// Validating the current highlight means bringing `tail` to `head`
+    pub fn validate(&mut self) {
+        self.base_offset += self.highlight_length;
+        self.base = unsafe { self.base.get_unchecked(self.highlight_length..) };
+        self.highlight_length = 0;
+        self.spanner_head.validate();
+        self.spanner_tail = self.spanner_head.clone();
+    }
+
+    /// Runs the closure on the current highlight, validating it if the result is ok.
+    pub fn then_validate<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&'s [u8]) -> Result<T, E>,
+    {
+        let res = f(self.highlight());
+        if res.is_ok() {
+            self.validate();
+        }
+        res
+    }
+
+    /// Returns the byte range of the current highlight, relative to the original input
+    pub fn span(&self) -> ByteSpan {
+        ByteSpan {
+            start: self.base_offset,
+            end: self.base_offset + self.highlight_length,
+        }
+    }
+}
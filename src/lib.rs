@@ -13,11 +13,26 @@
 //! `tail`, delimiting a (current) highlight `[tail,head[`. 
 //! Once tail has moved forward, it cannot move backward.
 
+pub mod byte_cursor;
 pub mod pattern;
 pub mod spanner;
 #[doc(inline)]
+pub use byte_cursor::ByteCursor;
+#[doc(inline)]
 pub use spanner::Spanner;
 
+/// A byte range `[start, end[` in the original input slice
+///
+/// Returned by [`StrCursor::span`]. Unlike `base`, which [`StrCursor::validate`]
+/// reslices and rebases to `0`, a [`ByteSpan`] is always expressed relative to the
+/// original input a [`StrCursor`] was created from, so it stays stable across
+/// validations and can safely be stashed away in an AST node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
 /// A cursor on `str` slices
 ///
 /// It is parametred by a type that should implement the [`Spanner`] trait. A spanner
@@ -27,6 +42,9 @@ pub use spanner::Spanner;
 pub struct StrCursor<'s, S> {
     base: &'s str,
     highlight_length: usize,
+    /// Byte offset of `base` relative to the original input, accumulated across
+    /// successive [`StrCursor::validate`] calls.
+    base_offset: usize,
     pub spanner_tail: S,
     pub spanner_head: S,
 }
@@ -44,6 +62,7 @@ impl<'s, S: Spanner> StrCursor<'s, S> {
         Self {
             base: s,
             highlight_length: 0,
+            base_offset: 0,
             spanner_head: spanner.clone(),
             spanner_tail: spanner,
         }
@@ -108,16 +127,85 @@ impl<'s, S: Spanner> StrCursor<'s, S> {
         res
     }
 
+    /// Advances `head` while the leading character of the post slice matches `pat`.
+    ///
+    /// `pat` behaves as in [`std::str::pattern::Pattern`]. For a closure, this is the
+    /// natural "take while predicate" form; for `char`/`&str`/`&[char]` patterns, a
+    /// character matches if the post slice at that point starts with something `pat`
+    /// would match at offset `0`.
+    ///
+    /// The returned slice may be empty if the leading character doesn't match.
+    pub fn step_while<P>(&mut self, mut pat: P) -> &'s str
+    where
+        P: pattern::Pattern,
+    {
+        let rem_str = self.post();
+        let mut offset = 0;
+        while offset < rem_str.len() {
+            let rest = unsafe { rem_str.get_unchecked(offset..) };
+            if !pat.matches_start(rest) {
+                break;
+            }
+            offset += rest.chars().next().unwrap().len_utf8();
+        }
+        let res = unsafe { rem_str.get_unchecked(..offset) };
+        self.highlight_length += offset;
+        self.spanner_head.forward_str(res);
+        res
+    }
+
+    /// Retreats `head` toward `tail` until the most recent match of `pat`, stopping
+    /// right after the matched character so it stays part of the highlight.
+    ///
+    /// `pat` behaves as in [`std::str::pattern::Pattern`]. This is the symmetric
+    /// counterpart of `step_until`, which stops just before the first match,
+    /// leaving the matched character in `post`.
+    ///
+    /// The returned slice is the part of the highlight that was un-highlighted.
+    /// It may be empty if the character just before `head` matches the pattern.
+    /// If no character matching the pattern is found in the current highlight,
+    /// `head` retreats all the way to `tail`.
+    pub fn unstep_until<P>(&mut self, pat: P) -> &'s str
+    where
+        P: pattern::Pattern,
+    {
+        let hl = self.highlight();
+        let offset = match pat.rfind(hl) {
+            Some(i) => {
+                let matched = unsafe { hl.get_unchecked(i..) };
+                // A zero-length match (e.g. an empty `&str` pattern) has no character
+                // to keep in the highlight, so it causes no retreat at all.
+                matched.chars().next().map_or(i, |c| i + c.len_utf8())
+            }
+            None => 0,
+        };
+        let res = unsafe { hl.get_unchecked(offset..) };
+        for c in res.chars().rev() {
+            self.spanner_head.backward(c);
+        }
+        self.highlight_length = offset;
+        res
+    }
+
     /// Validate the current highlight
-    /// 
+    ///
     /// Validating the current highlight means bringing `tail` to `head`
     pub fn validate(&mut self) {
+        self.base_offset += self.highlight_length;
         self.base = unsafe { self.base.get_unchecked(self.highlight_length..) };
         self.highlight_length = 0;
         self.spanner_head.validate();
         self.spanner_tail = self.spanner_head.clone();
     }
 
+    /// Returns the byte range of the current highlight, relative to the original input
+    pub fn span(&self) -> ByteSpan {
+        ByteSpan {
+            start: self.base_offset,
+            end: self.base_offset + self.highlight_length,
+        }
+    }
+
     /// Runs the closure on the current highlight, validating it if the result is ok.
     pub fn then_validate<T, E, F>(&mut self, f: F) -> Result<T, E>
     where
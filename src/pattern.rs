@@ -5,32 +5,69 @@
 /// [`std::str::pattern::Pattern`] on stable
 pub trait Pattern {
     fn find(self, s: &str) -> Option<usize>;
+    fn rfind(self, s: &str) -> Option<usize>;
+
+    /// Indicates whether the pattern matches at the very start of `s`
+    ///
+    /// Takes `&mut self` rather than `self`, unlike [`Pattern::find`], so it can be
+    /// called repeatedly against a moving window, e.g. from [`crate::StrCursor::step_while`].
+    fn matches_start(&mut self, s: &str) -> bool;
 }
 
 impl Pattern for char {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(*self)
+    }
 }
 impl<'b> Pattern for &'b str {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(*self)
+    }
 }
 impl<'b> Pattern for &'b [char] {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(*self)
+    }
 }
 impl<'b, 'c> Pattern for &'c &'b str {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(*self)
+    }
 }
 impl<'b, const N: usize> Pattern for &'b [char; N] {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(*self)
+    }
 }
 impl<F> Pattern for F
 where
@@ -39,14 +76,32 @@ where
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.chars().next().is_some_and(self)
+    }
 }
 impl<const N: usize> Pattern for [char; N] {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(*self)
+    }
 }
 impl<'b> Pattern for &'b String {
     fn find(self, s: &str) -> Option<usize> {
         s.find(self)
     }
+    fn rfind(self, s: &str) -> Option<usize> {
+        s.rfind(self)
+    }
+    fn matches_start(&mut self, s: &str) -> bool {
+        s.starts_with(self.as_str())
+    }
 }
@@ -1,6 +1,8 @@
 //! A module containing different spanners made to keep track
 //! of the location in a slice
 
+use std::rc::Rc;
+
 pub trait Spanner : Clone {
     fn forward(&mut self, c: char);
     fn backward(&mut self, c: char);
@@ -110,3 +112,66 @@ impl Spanner for RowColSpanner {
 
     // forxard_str left to default implem
 }
+
+/// A spanner keeping track of rows and columns from a precomputed line table
+///
+/// Built once from the full input, it tracks only the current byte offset in
+/// `forward`/`backward`/`forward_str` (maintained from `c.len_utf8()`, since
+/// [`Spanner::forward`] isn't given one). Converting that offset to a row/column
+/// is a binary search in the line table rather than a stack walk, so unlike
+/// [`RowColSpanner`], `backward` and [`Spanner::validate`] are O(1) and need no
+/// stack, and random jumps cost a single lookup.
+///
+/// **Column semantics differ from [`RowColSpanner`]**: [`IndexedRowColSpanner::col`]
+/// is a *byte* offset from the start of the row, whereas `RowColSpanner::col` counts
+/// non-control *characters*. Swapping one spanner for the other in an existing parser
+/// will shift reported columns for any line containing multi-byte characters.
+#[derive(Debug, Clone)]
+pub struct IndexedRowColSpanner {
+    line_starts: Rc<[usize]>,
+    offset: usize,
+}
+
+impl IndexedRowColSpanner {
+    /// Builds the spanner from the full input, scanning it once for line starts
+    pub fn new(s: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            s.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'\n')
+                .map(|(i, _)| i + 1),
+        );
+        Self {
+            line_starts: line_starts.into(),
+            offset: 0,
+        }
+    }
+
+    /// The current row, `0`-indexed
+    pub fn row(&self) -> usize {
+        self.line_starts.partition_point(|&start| start <= self.offset) - 1
+    }
+
+    /// The current column, as a **byte** offset from the start of the row
+    ///
+    /// Note this counts bytes, not characters like `RowColSpanner::col` does; see
+    /// the type-level documentation.
+    pub fn col(&self) -> usize {
+        self.offset - self.line_starts[self.row()]
+    }
+}
+
+impl Spanner for IndexedRowColSpanner {
+    fn forward(&mut self, c: char) {
+        self.offset += c.len_utf8();
+    }
+
+    fn backward(&mut self, c: char) {
+        self.offset -= c.len_utf8();
+    }
+
+    fn validate(&mut self) {}
+
+    // forward_str left to default implem
+}